@@ -0,0 +1,21 @@
+#![no_main]
+use libfuzzer_sys::fuzz_target;
+use nyxx_lexer::Lexer;
+
+// The lexer must not panic and the spans of the tokens must
+// cover the whole input, one after another, with no gaps.
+fuzz_target!(|source: &str| {
+    let mut offset = 0;
+    let mut position = (1, 0);
+
+    for token in Lexer::iter(source) {
+        let span = token.span();
+        assert_eq!(offset, span.range().start);
+        assert_eq!(position, span.start());
+
+        offset = span.range().end;
+        position = span.end();
+    }
+
+    assert_eq!(source.len(), offset);
+});
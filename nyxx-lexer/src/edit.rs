@@ -0,0 +1,180 @@
+use std::ops::Range;
+
+use super::{LexContext, Lexer, Token, TokenValue};
+
+/// Represents an edit of a source string: the bytes in the range
+/// are replaced with the text.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TextEdit {
+    /// The byte range of the source which is replaced
+    pub range: Range<usize>,
+    /// The replacement text
+    pub text: String,
+}
+
+impl TextEdit {
+    /// Create a new text edit
+    pub fn new(range: Range<usize>, text: &str) -> Self {
+        Self {
+            range,
+            text: text.to_string(),
+        }
+    }
+
+    /// Returns the source string after the edit was applied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of the source bounds or does not
+    /// fall on character boundaries.
+    pub fn apply(&self, source: &str) -> String {
+        let mut buffer = String::with_capacity(source.len() + self.text.len());
+        buffer.push_str(&source[..self.range.start]);
+        buffer.push_str(&self.text);
+        buffer.push_str(&source[self.range.end..]);
+        buffer
+    }
+}
+
+impl Lexer {
+    /// Returns the tokens of the edited source, given the tokens of the
+    /// original source and the edit. Only the tokens between the edit and
+    /// the point where the token stream re-synchronizes are lexed again,
+    /// the rest of the tokens are reused with their spans moved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use nyxx_lexer::{Lexer, TextEdit};
+    ///
+    /// let source = "var language = \"lox\";";
+    /// let tokens = Lexer::iter(source).collect();
+    ///
+    /// let edit = TextEdit::new(4..12, "lang");
+    /// let tokens = Lexer::relex(tokens, source, &edit);
+    /// assert_eq!(tokens, Lexer::iter(&edit.apply(source)).collect::<Vec<_>>());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the edit cannot be applied to the source, see [TextEdit::apply].
+    pub fn relex(tokens: Vec<Token>, source: &str, edit: &TextEdit) -> Vec<Token> {
+        let new_source = edit.apply(source);
+        let edit_end = edit.range.start + edit.text.len();
+
+        let mut old = tokens.into_iter().peekable();
+        let mut result = Vec::new();
+
+        // A token is decided by its characters and by the character right
+        // after it, so the tokens ending before the edit are not affected.
+        while let Some(token) = old.next_if(|t| t.span().end_offset < edit.range.start) {
+            result.push(token);
+        }
+
+        let restart = match old.peek() {
+            Some(token) => token.span().at_start(),
+            None => return Lexer::iter(&new_source).collect(),
+        };
+
+        let mut ctx = LexContext::resume(&new_source, &restart);
+        while let Some(token) = ctx.read_token() {
+            let boundary = token.span().at_end();
+            let eof = matches!(token.value(), TokenValue::Eof);
+            result.push(token);
+
+            if eof || boundary.end_offset < edit_end {
+                continue;
+            }
+
+            // Past the edit the text is the same as the original one, so
+            // once a new token ends where an old token starts, the rest
+            // of the old tokens are still valid.
+            let old_boundary = boundary.end_offset + edit.range.end - edit_end;
+            while old
+                .next_if(|t| t.span().start_offset < old_boundary)
+                .is_some()
+            {}
+
+            if let Some(token) = old.peek() {
+                if token.span().start_offset == old_boundary {
+                    let from = token.span().at_start();
+                    result.extend(old.map(|mut t| {
+                        t.shift(&from, &boundary);
+                        t
+                    }));
+                    break;
+                }
+            }
+        }
+
+        result
+    }
+}
+
+//
+// Tests
+//
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(source: &str, edit: TextEdit) {
+        let tokens = Lexer::iter(source).collect();
+        let relexed = Lexer::relex(tokens, source, &edit);
+        let expected: Vec<Token> = Lexer::iter(&edit.apply(source)).collect();
+        assert_eq!(expected, relexed);
+    }
+
+    const SOURCE: &str = "var language = \"lox\";\nprint language; // done\nvar x = 10;";
+
+    #[test]
+    fn test_apply() {
+        let edit = TextEdit::new(4..12, "lang");
+        assert_eq!("var lang;", edit.apply("var language;"));
+    }
+
+    #[test]
+    fn test_relex_inside_token() {
+        check(SOURCE, TextEdit::new(6..8, "XYZ"));
+    }
+
+    #[test]
+    fn test_relex_extends_token() {
+        check(SOURCE, TextEdit::new(12..12, "s"));
+        check(SOURCE, TextEdit::new(3..4, ""));
+    }
+
+    #[test]
+    fn test_relex_new_line() {
+        check(SOURCE, TextEdit::new(21..22, ""));
+        check(SOURCE, TextEdit::new(4..4, "\n\n"));
+    }
+
+    #[test]
+    fn test_relex_string() {
+        check(SOURCE, TextEdit::new(16..16, "\"; \""));
+        check(SOURCE, TextEdit::new(15..20, "\"a\nb\""));
+    }
+
+    #[test]
+    fn test_relex_comment() {
+        check(SOURCE, TextEdit::new(38..38, "//"));
+        check(SOURCE, TextEdit::new(38..46, ""));
+    }
+
+    #[test]
+    fn test_relex_bounds() {
+        check(SOURCE, TextEdit::new(0..0, "  "));
+        check(SOURCE, TextEdit::new(SOURCE.len()..SOURCE.len(), "\nx;"));
+        check(SOURCE, TextEdit::new(0..SOURCE.len(), "nil"));
+        check("", TextEdit::new(0..0, "nil"));
+        check("nil", TextEdit::new(0..3, ""));
+    }
+
+    #[test]
+    fn test_relex_multibyte() {
+        check("\"é\"; x;", TextEdit::new(1..3, "ab"));
+        check("\"ab\"; x;", TextEdit::new(1..3, "é"));
+    }
+}
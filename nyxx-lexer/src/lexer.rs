@@ -7,10 +7,12 @@ const CHAR_LEFT_PAREN: char = '(';
 const CHAR_RIGHT_PAREN: char = ')';
 const CHAR_LEFT_BRACE: char = '{';
 const CHAR_RIGHT_BRACE: char = '}';
+const CHAR_COLON: char = ':';
 const CHAR_COMMA: char = ',';
 const CHAR_DOT: char = '.';
 const CHAR_MINUS: char = '-';
 const CHAR_PLUS: char = '+';
+const CHAR_PERCENT: char = '%';
 const CHAR_SEMICOLON: char = ';';
 const CHAR_STAR: char = '*';
 const CHAR_BANG: char = '!';
@@ -18,6 +20,11 @@ const CHAR_EQUAL: char = '=';
 const CHAR_LESS: char = '<';
 const CHAR_GREATER: char = '>';
 const CHAR_SLASH: char = '/';
+const CHAR_QUESTION: char = '?';
+const CHAR_AMPERSAND: char = '&';
+const CHAR_PIPE: char = '|';
+const CHAR_CARET: char = '^';
+const CHAR_TILDE: char = '~';
 const CHAR_DOUBLE_QUOTE: char = '"';
 const CHAR_NEWLINE: char = '\n';
 const CHAR_WHITESPACE: char = ' ';
@@ -33,19 +40,28 @@ const CHAR_UNDERSCORE: char = '_';
 
 static KEYWORDS: &[(&str, TokenValue)] = &[
     ("and", TokenValue::And),
+    ("as", TokenValue::As),
+    ("break", TokenValue::Break),
+    ("catch", TokenValue::Catch),
     ("class", TokenValue::Class),
+    ("continue", TokenValue::Continue),
     ("else", TokenValue::Else),
     ("false", TokenValue::False),
+    ("finally", TokenValue::Finally),
     ("for", TokenValue::For),
+    ("from", TokenValue::From),
     ("fun", TokenValue::Fun),
     ("if", TokenValue::If),
+    ("import", TokenValue::Import),
     ("nil", TokenValue::Nil),
     ("or", TokenValue::Or),
     ("print", TokenValue::Print),
     ("return", TokenValue::Return),
     ("super", TokenValue::Super),
     ("this", TokenValue::This),
+    ("throw", TokenValue::Throw),
     ("true", TokenValue::True),
+    ("try", TokenValue::Try),
     ("var", TokenValue::Var),
     ("while", TokenValue::While),
 ];
@@ -54,7 +70,7 @@ static KEYWORDS: &[(&str, TokenValue)] = &[
 // Lexer context
 //
 
-struct LexContext<'a> {
+pub(crate) struct LexContext<'a> {
     source: Peekable<Chars<'a>>,
     span: Span,
     eof_sent: bool,
@@ -70,6 +86,16 @@ impl<'a> LexContext<'a> {
         }
     }
 
+    /// Creates a lex context which starts reading the source at the
+    /// beginning of the given span.
+    pub(crate) fn resume(source: &'a str, span: &Span) -> Self {
+        Self {
+            source: source[span.start_offset..].chars().peekable(),
+            span: span.at_start(),
+            eof_sent: false,
+        }
+    }
+
     pub(crate) fn read_token(&mut self) -> Option<Token> {
         if self.eof_sent {
             return None;
@@ -82,12 +108,63 @@ impl<'a> LexContext<'a> {
                 CHAR_RIGHT_PAREN => Some(self.right_paren()),
                 CHAR_LEFT_BRACE => Some(self.left_brace()),
                 CHAR_RIGHT_BRACE => Some(self.right_brace()),
+                CHAR_COLON => Some(self.colon()),
                 CHAR_COMMA => Some(self.comma()),
                 CHAR_DOT => Some(self.dot()),
-                CHAR_MINUS => Some(self.minus()),
-                CHAR_PLUS => Some(self.plus()),
+                CHAR_PERCENT => Some(self.percent()),
                 CHAR_SEMICOLON => Some(self.semicolon()),
-                CHAR_STAR => Some(self.star()),
+                CHAR_AMPERSAND => Some(self.ampersand()),
+                CHAR_PIPE => Some(self.pipe()),
+                CHAR_CARET => Some(self.caret()),
+                CHAR_TILDE => Some(self.tilde()),
+                CHAR_MINUS => match self.peek_char() {
+                    Some(CHAR_MINUS) => {
+                        let _ = self.read_char().unwrap();
+                        Some(self.minus_minus())
+                    }
+                    Some(CHAR_EQUAL) => {
+                        let _ = self.read_char().unwrap();
+                        Some(self.minus_equal())
+                    }
+                    Some(CHAR_GREATER) => {
+                        let _ = self.read_char().unwrap();
+                        Some(self.arrow())
+                    }
+                    _ => Some(self.minus()),
+                },
+                CHAR_PLUS => match self.peek_char() {
+                    Some(CHAR_PLUS) => {
+                        let _ = self.read_char().unwrap();
+                        Some(self.plus_plus())
+                    }
+                    Some(CHAR_EQUAL) => {
+                        let _ = self.read_char().unwrap();
+                        Some(self.plus_equal())
+                    }
+                    _ => Some(self.plus()),
+                },
+                CHAR_STAR => match self.peek_char() {
+                    Some(CHAR_STAR) => {
+                        let _ = self.read_char().unwrap();
+                        Some(self.star_star())
+                    }
+                    Some(CHAR_EQUAL) => {
+                        let _ = self.read_char().unwrap();
+                        Some(self.star_equal())
+                    }
+                    _ => Some(self.star()),
+                },
+                CHAR_QUESTION => match self.peek_char() {
+                    Some(CHAR_QUESTION) => {
+                        let _ = self.read_char().unwrap();
+                        Some(self.question_question())
+                    }
+                    Some(CHAR_DOT) => {
+                        let _ = self.read_char().unwrap();
+                        Some(self.question_dot())
+                    }
+                    _ => Some(self.question()),
+                },
                 CHAR_BANG => {
                     if let Some(CHAR_EQUAL) = self.peek_char() {
                         let _ = self.read_char().unwrap();
@@ -104,37 +181,44 @@ impl<'a> LexContext<'a> {
                         Some(self.equal())
                     }
                 }
-                CHAR_LESS => {
-                    if let Some(CHAR_EQUAL) = self.peek_char() {
+                CHAR_LESS => match self.peek_char() {
+                    Some(CHAR_EQUAL) => {
                         let _ = self.read_char().unwrap();
                         Some(self.less_equal())
-                    } else {
-                        Some(self.less())
                     }
-                }
-                CHAR_GREATER => {
-                    if let Some(CHAR_EQUAL) = self.peek_char() {
+                    Some(CHAR_LESS) => {
+                        let _ = self.read_char().unwrap();
+                        Some(self.less_less())
+                    }
+                    _ => Some(self.less()),
+                },
+                CHAR_GREATER => match self.peek_char() {
+                    Some(CHAR_EQUAL) => {
                         let _ = self.read_char().unwrap();
                         Some(self.greater_equal())
-                    } else {
-                        Some(self.greater())
                     }
-                }
-                CHAR_SLASH => {
-                    if let Some(CHAR_SLASH) = self.peek_char() {
+                    Some(CHAR_GREATER) => {
+                        let _ = self.read_char().unwrap();
+                        Some(self.greater_greater())
+                    }
+                    _ => Some(self.greater()),
+                },
+                CHAR_SLASH => match self.peek_char() {
+                    Some(CHAR_SLASH) => {
                         let cmnt = self.read_line();
                         Some(self.comment(cmnt))
-                    } else {
-                        Some(self.slash())
                     }
-                }
+                    Some(CHAR_EQUAL) => {
+                        let _ = self.read_char().unwrap();
+                        Some(self.slash_equal())
+                    }
+                    _ => Some(self.slash()),
+                },
                 CHAR_DOUBLE_QUOTE => {
                     if let Some(s) = self.read_string() {
-                        println!("Did we get here?");
                         Some(self.string(s))
                     } else {
-                        log::error!("We didnt finish the string");
-                        panic!("We should finish the string")
+                        Some(self.error("Unterminated string".to_string()))
                     }
                 }
                 CHAR_NEWLINE => Some(self.newline()),
@@ -147,8 +231,7 @@ impl<'a> LexContext<'a> {
                     if let Some(number) = self.read_number(digit) {
                         Some(self.number(number))
                     } else {
-                        log::error!("We didnt finish the number");
-                        panic!("We should finish the number")
+                        Some(self.error("Expected digits after '.'".to_string()))
                     }
                 }
                 // Alpha
@@ -163,7 +246,7 @@ impl<'a> LexContext<'a> {
 
                     Some(token)
                 }
-                unexpected => panic!("Unknown char {}", unexpected),
+                unexpected => Some(self.error(format!("Unexpected character '{}'", unexpected))),
             };
 
             match_token
@@ -183,11 +266,10 @@ impl<'a> LexContext<'a> {
         let mut buffer = String::new();
 
         for c in &mut self.source {
-            self.span.new_column();
+            self.span.advance(c);
             buffer.push(c);
 
             if c == CHAR_NEWLINE {
-                self.span.new_line();
                 break;
             }
         }
@@ -198,11 +280,7 @@ impl<'a> LexContext<'a> {
     /// Read the next character in the stream
     fn read_char(&mut self) -> Option<char> {
         if let Some(c) = self.source.next() {
-            self.span.new_column();
-            if c == CHAR_NEWLINE {
-                self.span.new_line();
-            }
-
+            self.span.advance(c);
             Some(c)
         } else {
             None
@@ -231,10 +309,7 @@ impl<'a> LexContext<'a> {
         let mut string_terminated = false;
 
         for c in &mut self.source {
-            self.span.new_column();
-            if c == CHAR_NEWLINE {
-                self.span.new_line();
-            }
+            self.span.advance(c);
 
             if c == CHAR_DOUBLE_QUOTE {
                 string_terminated = true;
@@ -297,11 +372,7 @@ impl<'a> LexContext<'a> {
             }
         }
 
-        if let Ok(number) = f64::from_str(&buffer) {
-            Some(number)
-        } else {
-            None
-        }
+        f64::from_str(&buffer).ok()
     }
 
     fn read_ws(&mut self, first_ws: char) -> String {
@@ -343,6 +414,11 @@ impl<'a> LexContext<'a> {
         Token::right_brace(self.span.extract())
     }
 
+    #[inline]
+    fn colon(&mut self) -> Token {
+        Token::colon(self.span.extract())
+    }
+
     #[inline]
     fn comma(&mut self) -> Token {
         Token::comma(self.span.extract())
@@ -418,6 +494,96 @@ impl<'a> LexContext<'a> {
         Token::slash(self.span.extract())
     }
 
+    #[inline]
+    fn ampersand(&mut self) -> Token {
+        Token::ampersand(self.span.extract())
+    }
+
+    #[inline]
+    fn caret(&mut self) -> Token {
+        Token::caret(self.span.extract())
+    }
+
+    #[inline]
+    fn percent(&mut self) -> Token {
+        Token::percent(self.span.extract())
+    }
+
+    #[inline]
+    fn pipe(&mut self) -> Token {
+        Token::pipe(self.span.extract())
+    }
+
+    #[inline]
+    fn question(&mut self) -> Token {
+        Token::question(self.span.extract())
+    }
+
+    #[inline]
+    fn tilde(&mut self) -> Token {
+        Token::tilde(self.span.extract())
+    }
+
+    #[inline]
+    fn greater_greater(&mut self) -> Token {
+        Token::greater_greater(self.span.extract())
+    }
+
+    #[inline]
+    fn less_less(&mut self) -> Token {
+        Token::less_less(self.span.extract())
+    }
+
+    #[inline]
+    fn minus_equal(&mut self) -> Token {
+        Token::minus_equal(self.span.extract())
+    }
+
+    #[inline]
+    fn minus_minus(&mut self) -> Token {
+        Token::minus_minus(self.span.extract())
+    }
+
+    #[inline]
+    fn plus_equal(&mut self) -> Token {
+        Token::plus_equal(self.span.extract())
+    }
+
+    #[inline]
+    fn plus_plus(&mut self) -> Token {
+        Token::plus_plus(self.span.extract())
+    }
+
+    #[inline]
+    fn question_dot(&mut self) -> Token {
+        Token::question_dot(self.span.extract())
+    }
+
+    #[inline]
+    fn question_question(&mut self) -> Token {
+        Token::question_question(self.span.extract())
+    }
+
+    #[inline]
+    fn slash_equal(&mut self) -> Token {
+        Token::slash_equal(self.span.extract())
+    }
+
+    #[inline]
+    fn star_equal(&mut self) -> Token {
+        Token::star_equal(self.span.extract())
+    }
+
+    #[inline]
+    fn star_star(&mut self) -> Token {
+        Token::star_star(self.span.extract())
+    }
+
+    #[inline]
+    fn arrow(&mut self) -> Token {
+        Token::arrow(self.span.extract())
+    }
+
     #[inline]
     fn comment(&mut self, c: String) -> Token {
         Token::comment(c, self.span.extract())
@@ -443,6 +609,12 @@ impl<'a> LexContext<'a> {
         Token::number(number, self.span.extract())
     }
 
+    #[inline]
+    fn error(&mut self, e: String) -> Token {
+        log::debug!("{} at {}", e, self.span);
+        Token::error(e, self.span.extract())
+    }
+
     #[inline]
     fn identifier(&mut self, i: String) -> Token {
         Token::identifier(i, self.span.extract())
@@ -492,17 +664,32 @@ impl Lexer {
     /// Returns an iterator which containts the
     /// tokens resulted from parsing the source string.
     #[inline]
-    pub fn with_source(source: &str) -> LexerIter {
+    pub fn with_source(source: &str) -> LexerIter<'_> {
         Lexer::iter(source)
     }
 
     /// Returns an iterator which containts the
     /// tokens resulted from parsing the source string.
-    pub fn iter(source: &str) -> LexerIter {
+    pub fn iter(source: &str) -> LexerIter<'_> {
         LexerIter {
             ctx: LexContext::new(source),
         }
     }
+
+    /// Returns an iterator which contains the tokens of the source string
+    /// starting at the beginning of the given span. The span must start on
+    /// a token boundary of the same source, e.g. the span of a token
+    /// returned by an earlier call to [Lexer::iter].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the span starts past the end of the source or not on a
+    /// character boundary.
+    pub fn resume<'a>(source: &'a str, span: &Span) -> LexerIter<'a> {
+        LexerIter {
+            ctx: LexContext::resume(source, span),
+        }
+    }
 }
 
 impl<'a> From<&'a str> for LexerIter<'a> {
@@ -544,6 +731,98 @@ fn is_whitespace(c: char) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use proptest::prelude::*;
+
+    fn lexeme() -> impl Strategy<Value = String> {
+        prop_oneof![
+            prop::sample::select(
+                KEYWORDS
+                    .iter()
+                    .map(|(k, _)| k.to_string())
+                    .collect::<Vec<_>>()
+            ),
+            "[a-zA-Z_][a-zA-Z0-9_]{0,8}",
+            "[0-9]{1,5}(\\.[0-9]{1,3})?",
+            "\"[a-z0-9 \n]{0,8}\"",
+            "//[a-z ]{0,8}\n",
+            prop::sample::select(vec![
+                "(", ")", "{", "}", ",", ".", "-", "+", ";", "*", "/", ":", "!", "!=", "=", "==",
+                "<", "<=", ">", ">=", "%", "**", "+=", "-=", "*=", "/=", "++", "--", "?", "&", "|",
+                "^", "~", "<<", ">>", "??", "?.", "->",
+            ])
+            .prop_map(String::from),
+        ]
+    }
+
+    fn source() -> impl Strategy<Value = String> {
+        let separator = prop::sample::select(vec![" ", "\t", "\n", "\r\n"]);
+        prop::collection::vec((lexeme(), separator), 0..32).prop_map(|parts| {
+            parts
+                .into_iter()
+                .map(|(lexeme, separator)| lexeme + separator)
+                .collect()
+        })
+    }
+
+    fn check_spans(source: &str) -> Result<(), TestCaseError> {
+        let mut offset = 0;
+        let mut position = (1, 0);
+
+        for token in Lexer::iter(source) {
+            let span = token.span();
+            prop_assert_eq!(offset, span.range().start);
+            prop_assert_eq!(position, span.start());
+
+            offset = span.range().end;
+            position = span.end();
+        }
+
+        prop_assert_eq!(source.len(), offset);
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn test_spans_cover_source(source in source()) {
+            check_spans(&source)?;
+        }
+
+        #[test]
+        fn test_spans_cover_any_source(source in any::<String>()) {
+            check_spans(&source)?;
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        let errors = |source| -> Vec<String> {
+            Lexer::iter(source)
+                .map(|t| format!("{:?}", t))
+                .filter(|t| t.starts_with("ERROR("))
+                .collect()
+        };
+
+        assert_eq!(
+            errors("a @ b"),
+            vec!["ERROR(Unexpected character '@') @ [1:2]"]
+        );
+        assert_eq!(
+            errors("\"abc"),
+            vec!["ERROR(Unterminated string) @ [1:0..4]"]
+        );
+        assert_eq!(
+            errors("1."),
+            vec!["ERROR(Expected digits after '.') @ [1:0..2]"]
+        );
+        assert_eq!(
+            errors("1.x"),
+            vec!["ERROR(Expected digits after '.') @ [1:0..2]"]
+        );
+
+        for source in ["@", "\"", "\"abc", "1."].iter() {
+            check_spans(source).unwrap();
+        }
+    }
 
     #[test]
     fn test_is_digit() {
@@ -552,4 +831,76 @@ mod tests {
         assert!(is_digit('9'));
         assert!(!is_digit('a'));
     }
+
+    #[test]
+    fn test_keywords_sorted() {
+        // The keyword lookup relies on a binary search.
+        assert!(KEYWORDS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_resume() {
+        let source = "var language =\n\"lox\";";
+        let tokens: Vec<Token> = Lexer::iter(source).collect();
+        let resumed: Vec<Token> = Lexer::resume(source, tokens[4].span()).collect();
+
+        assert_eq!(&tokens[4..], &resumed[..]);
+    }
+
+    #[test]
+    fn test_operators() {
+        let source = "% ** += -= *= /= ++ -- ? : & | ^ ~ << >> ?? ?. ->";
+        let tokens: Vec<String> = Lexer::iter(source)
+            .map(|t| format!("{:?}", t))
+            .filter(|t| !t.starts_with("WS("))
+            .map(|t| t.split(" @ ").next().unwrap().to_string())
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                "PERCENT",
+                "STAR_STAR",
+                "PLUS_EQUAL",
+                "MINUS_EQUAL",
+                "STAR_EQUAL",
+                "SLASH_EQUAL",
+                "PLUS_PLUS",
+                "MINUS_MINUS",
+                "QUESTION",
+                "COLON",
+                "AMPERSAND",
+                "PIPE",
+                "CARET",
+                "TILDE",
+                "LESS_LESS",
+                "GREATER_GREATER",
+                "QUESTION_QUESTION",
+                "QUESTION_DOT",
+                "ARROW",
+                "EOF",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_labelled_break() {
+        let tokens: Vec<String> = Lexer::iter("outer: break outer;")
+            .map(|t| format!("{:?}", t))
+            .collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                "IDENT(outer) @ [1:0..5]",
+                "COLON @ [1:5]",
+                "WS( ) @ [1:6]",
+                "BREAK @ [1:7..12]",
+                "WS( ) @ [1:12]",
+                "IDENT(outer) @ [1:13..18]",
+                "SEMICOLON @ [1:18]",
+                "EOF @ [1:19..19]",
+            ]
+        );
+    }
 }
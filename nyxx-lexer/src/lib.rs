@@ -3,10 +3,12 @@
 #![deny(missing_docs)]
 #![deny(unreachable_code)]
 
+mod edit;
 mod lexer;
 mod span;
 mod token;
 
+pub use crate::edit::*;
 pub use crate::lexer::*;
 pub use crate::span::*;
 pub use crate::token::*;
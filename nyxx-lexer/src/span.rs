@@ -1,13 +1,18 @@
 use std::fmt::{Debug, Display};
+use std::ops::Range;
 
 /// Represents a span in the text. It starts at a line:column and ends
 /// at the line_end:column_end (inclusive). Line starts at 1, column at 0.
+/// The span also keeps the byte offsets of its start and end in the source.
 #[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Span {
     pub(crate) start_line: usize,
     pub(crate) start_col: usize,
     pub(crate) end_line: usize,
     pub(crate) end_col: usize,
+    pub(crate) start_offset: usize,
+    pub(crate) end_offset: usize,
 }
 
 impl Span {
@@ -18,6 +23,8 @@ impl Span {
             start_col: 0,
             end_line: 1,
             end_col: 0,
+            start_offset: 0,
+            end_offset: 0,
         }
     }
 
@@ -31,6 +38,11 @@ impl Span {
         (self.end_line, self.end_col)
     }
 
+    /// Returns the byte range the span covers in the source.
+    pub fn range(&self) -> Range<usize> {
+        self.start_offset..self.end_offset
+    }
+
     pub(crate) fn new_column(&mut self) {
         self.end_col += 1;
     }
@@ -40,19 +52,63 @@ impl Span {
         self.end_col = 0;
     }
 
-    /// Extracts the current span and get ready to continue
-    pub fn extract(&mut self) -> Self {
-        // Get the current span values.
-        let span = Span {
+    /// Extends the span over the given character.
+    pub(crate) fn advance(&mut self, c: char) {
+        self.end_offset += c.len_utf8();
+        self.new_column();
+        if c == '\n' {
+            self.new_line();
+        }
+    }
+
+    /// Returns an empty span positioned at the start of this span.
+    pub(crate) fn at_start(&self) -> Self {
+        Span {
             start_line: self.start_line,
             start_col: self.start_col,
+            end_line: self.start_line,
+            end_col: self.start_col,
+            start_offset: self.start_offset,
+            end_offset: self.start_offset,
+        }
+    }
+
+    /// Returns an empty span positioned at the end of this span.
+    pub(crate) fn at_end(&self) -> Self {
+        Span {
+            start_line: self.end_line,
+            start_col: self.end_col,
             end_line: self.end_line,
             end_col: self.end_col,
-        };
+            start_offset: self.end_offset,
+            end_offset: self.end_offset,
+        }
+    }
+
+    /// Moves a span which lies after the `from` position so that it keeps
+    /// the same distance to the `to` position. Columns only change on the
+    /// line of the `from` position, later lines keep their columns.
+    pub(crate) fn shift(&mut self, from: &Span, to: &Span) {
+        let (start_line, start_col) = shift_position(self.start(), from.start(), to.start());
+        let (end_line, end_col) = shift_position(self.end(), from.start(), to.start());
+
+        self.start_line = start_line;
+        self.start_col = start_col;
+        self.end_line = end_line;
+        self.end_col = end_col;
+        self.start_offset = self.start_offset - from.start_offset + to.start_offset;
+        self.end_offset = self.end_offset - from.start_offset + to.start_offset;
+    }
+
+    /// Extracts the current span and get ready to continue
+    pub fn extract(&mut self) -> Self {
+        // Get the current span values.
+        let span = self.clone();
 
         // Prep a new starting span
         self.start_line = self.end_line;
         self.start_col = self.end_col;
+        self.start_offset = self.end_offset;
 
         span
     }
@@ -74,6 +130,17 @@ impl PartialEq for Span {
             && self.start_col == other.start_col
             && self.end_line == other.end_line
             && self.end_col == other.end_col
+            && self.start_offset == other.start_offset
+            && self.end_offset == other.end_offset
+    }
+}
+
+fn shift_position(pos: (usize, usize), from: (usize, usize), to: (usize, usize)) -> (usize, usize) {
+    let line = pos.0 - from.0 + to.0;
+    if pos.0 == from.0 {
+        (line, pos.1 - from.1 + to.1)
+    } else {
+        (line, pos.1)
     }
 }
 
@@ -207,6 +274,53 @@ mod tests {
         assert_eq!(0, span.end_col);
     }
 
+    #[test]
+    fn test_advance() {
+        let mut span = Span::default();
+        span.advance('a');
+        span.advance('é');
+        span.advance('\n');
+
+        assert_eq!((1, 0), span.start());
+        assert_eq!((2, 0), span.end());
+        assert_eq!(0..4, span.range());
+
+        let span1 = span.extract();
+        assert_eq!(0..4, span1.range());
+        assert_eq!(4..4, span.range());
+    }
+
+    #[test]
+    fn test_shift() {
+        let mut span = Span::default();
+        "ab\ncd".chars().for_each(|c| span.advance(c));
+        let from = span.extract();
+
+        // A token on the same line as the `from` position.
+        span.advance('e');
+        let mut same_line = span.extract();
+
+        // A token on a later line.
+        span.advance('\n');
+        let _ = span.extract();
+        span.advance('f');
+        let mut next_line = span.extract();
+
+        let mut to = Span::default();
+        "x".chars().for_each(|c| to.advance(c));
+        let to = to.at_end();
+
+        same_line.shift(&from.at_end(), &to);
+        assert_eq!((1, 1), same_line.start());
+        assert_eq!((1, 2), same_line.end());
+        assert_eq!(1..2, same_line.range());
+
+        next_line.shift(&from.at_end(), &to);
+        assert_eq!((2, 0), next_line.start());
+        assert_eq!((2, 1), next_line.end());
+        assert_eq!(3..4, next_line.range());
+    }
+
     #[test]
     fn test_display() {
         let mut span = Span::default();
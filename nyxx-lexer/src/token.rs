@@ -2,7 +2,8 @@ use super::Span;
 use std::fmt::{Debug, Display};
 
 /// Represents the tokens supported by the language.
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TokenValue {
     // Single-char tokens
     /// Left paren
@@ -27,6 +28,20 @@ pub enum TokenValue {
     Slash,
     /// Start
     Star,
+    /// Colon
+    Colon,
+    /// Percent
+    Percent,
+    /// Question
+    Question,
+    /// Ampersand
+    Ampersand,
+    /// Pipe
+    Pipe,
+    /// Caret
+    Caret,
+    /// Tilde
+    Tilde,
     // One or two characters tokens
     /// Bang
     Bang,
@@ -44,6 +59,30 @@ pub enum TokenValue {
     Less,
     /// LessEqual
     LessEqual,
+    /// StarStar
+    StarStar,
+    /// PlusEqual
+    PlusEqual,
+    /// MinusEqual
+    MinusEqual,
+    /// StarEqual
+    StarEqual,
+    /// SlashEqual
+    SlashEqual,
+    /// PlusPlus
+    PlusPlus,
+    /// MinusMinus
+    MinusMinus,
+    /// LessLess
+    LessLess,
+    /// GreaterGreater
+    GreaterGreater,
+    /// QuestionQuestion
+    QuestionQuestion,
+    /// QuestionDot
+    QuestionDot,
+    /// Arrow
+    Arrow,
     // Literals
     /// Identity
     Ident(String),
@@ -56,18 +95,32 @@ pub enum TokenValue {
     // Keywords
     /// And
     And,
+    /// As
+    As,
+    /// Break
+    Break,
+    /// Catch
+    Catch,
     /// Class
     Class,
+    /// Continue
+    Continue,
     /// Else
     Else,
     /// False
     False,
+    /// Finally
+    Finally,
     /// Fun
     Fun,
     /// For
     For,
+    /// From
+    From,
     /// If
     If,
+    /// Import
+    Import,
     /// Nil
     Nil,
     /// Or
@@ -80,8 +133,12 @@ pub enum TokenValue {
     Super,
     /// This
     This,
+    /// Throw
+    Throw,
     /// True
     True,
+    /// Try
+    Try,
     /// Var
     Var,
     /// While
@@ -93,50 +150,81 @@ pub enum TokenValue {
     NewLine,
     /// EOF
     Eof,
+    /// Lexing error, e.g. an unexpected character
+    Error(String),
 }
 
 impl Debug for TokenValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            TokenValue::Ampersand => write!(f, "AMPERSAND"),
             TokenValue::And => write!(f, "AND"),
+            TokenValue::Arrow => write!(f, "ARROW"),
+            TokenValue::As => write!(f, "AS"),
             TokenValue::Bang => write!(f, "BANG"),
             TokenValue::BangEqual => write!(f, "BANG_EQUAL"),
+            TokenValue::Break => write!(f, "BREAK"),
+            TokenValue::Caret => write!(f, "CARET"),
+            TokenValue::Catch => write!(f, "CATCH"),
             TokenValue::Class => write!(f, "CLASS"),
+            TokenValue::Colon => write!(f, "COLON"),
             TokenValue::Comma => write!(f, "COMMA"),
             TokenValue::Comment(c) => write!(f, "COMM({})", c),
+            TokenValue::Continue => write!(f, "CONTINUE"),
             TokenValue::Dot => write!(f, "DOT"),
             TokenValue::Else => write!(f, "ELSE"),
             TokenValue::Eof => write!(f, "EOF"),
+            TokenValue::Error(e) => write!(f, "ERROR({})", e),
             TokenValue::Equal => write!(f, "EQUAL"),
             TokenValue::EqualEqual => write!(f, "EQUAL_EQUAL"),
             TokenValue::False => write!(f, "FALSE"),
+            TokenValue::Finally => write!(f, "FINALLY"),
             TokenValue::For => write!(f, "FOR"),
+            TokenValue::From => write!(f, "FROM"),
             TokenValue::Fun => write!(f, "FUN"),
             TokenValue::Greater => write!(f, "GREATER"),
             TokenValue::GreaterEqual => write!(f, "GREATER_EQUAL"),
+            TokenValue::GreaterGreater => write!(f, "GREATER_GREATER"),
             TokenValue::Ident(s) => write!(f, "IDENT({})", s),
             TokenValue::If => write!(f, "IF"),
+            TokenValue::Import => write!(f, "IMPORT"),
             TokenValue::LeftBrace => write!(f, "LEFT_BRACE"),
             TokenValue::LeftParen => write!(f, "LEFT_PAREN"),
             TokenValue::Less => write!(f, "LESS"),
             TokenValue::LessEqual => write!(f, "LESS_EQUAL"),
+            TokenValue::LessLess => write!(f, "LESS_LESS"),
             TokenValue::Minus => write!(f, "MINUS"),
+            TokenValue::MinusEqual => write!(f, "MINUS_EQUAL"),
+            TokenValue::MinusMinus => write!(f, "MINUS_MINUS"),
             TokenValue::NewLine => write!(f, "NEWLINE"),
             TokenValue::Nil => write!(f, "NIL"),
             TokenValue::Number(n) => write!(f, "NUMBER({})", n),
             TokenValue::Or => write!(f, "OR"),
+            TokenValue::Percent => write!(f, "PERCENT"),
+            TokenValue::Pipe => write!(f, "PIPE"),
             TokenValue::Plus => write!(f, "PLUS"),
+            TokenValue::PlusEqual => write!(f, "PLUS_EQUAL"),
+            TokenValue::PlusPlus => write!(f, "PLUS_PLUS"),
             TokenValue::Print => write!(f, "PRINT"),
+            TokenValue::Question => write!(f, "QUESTION"),
+            TokenValue::QuestionDot => write!(f, "QUESTION_DOT"),
+            TokenValue::QuestionQuestion => write!(f, "QUESTION_QUESTION"),
             TokenValue::Return => write!(f, "RETURN"),
             TokenValue::RightBrace => write!(f, "RIGHT_BRACE"),
             TokenValue::RightParen => write!(f, "RIGHT_PAREN"),
             TokenValue::Semicolon => write!(f, "SEMICOLON"),
             TokenValue::Slash => write!(f, "SLASH"),
+            TokenValue::SlashEqual => write!(f, "SLASH_EQUAL"),
             TokenValue::Star => write!(f, "STAR"),
+            TokenValue::StarEqual => write!(f, "STAR_EQUAL"),
+            TokenValue::StarStar => write!(f, "STAR_STAR"),
             TokenValue::String(s) => write!(f, "STRING({})", s),
             TokenValue::Super => write!(f, "SUPER"),
             TokenValue::This => write!(f, "THIS"),
+            TokenValue::Throw => write!(f, "THROW"),
+            TokenValue::Tilde => write!(f, "TILDE"),
             TokenValue::True => write!(f, "TRUE"),
+            TokenValue::Try => write!(f, "TRY"),
             TokenValue::Var => write!(f, "VAR"),
             TokenValue::Whitespace(w) => write!(f, "WS({})", w),
             TokenValue::While => write!(f, "WHILE"),
@@ -147,45 +235,74 @@ impl Debug for TokenValue {
 impl Display for TokenValue {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            TokenValue::Ampersand => write!(f, "&"),
             TokenValue::And => write!(f, "and"),
+            TokenValue::Arrow => write!(f, "->"),
+            TokenValue::As => write!(f, "as"),
             TokenValue::Bang => write!(f, "!"),
             TokenValue::BangEqual => write!(f, "!="),
+            TokenValue::Break => write!(f, "break"),
+            TokenValue::Caret => write!(f, "^"),
+            TokenValue::Catch => write!(f, "catch"),
             TokenValue::Class => write!(f, "class"),
+            TokenValue::Colon => write!(f, ":"),
             TokenValue::Comma => write!(f, ","),
             TokenValue::Comment(c) => write!(f, "// {}", c),
+            TokenValue::Continue => write!(f, "continue"),
             TokenValue::Dot => write!(f, "."),
             TokenValue::Else => write!(f, "else"),
             TokenValue::Eof => write!(f, "eof"),
+            TokenValue::Error(e) => write!(f, "{}", e),
             TokenValue::Equal => write!(f, "="),
             TokenValue::EqualEqual => write!(f, "=="),
             TokenValue::False => write!(f, "false"),
+            TokenValue::Finally => write!(f, "finally"),
             TokenValue::For => write!(f, "for"),
+            TokenValue::From => write!(f, "from"),
             TokenValue::Fun => write!(f, "fun"),
             TokenValue::Greater => write!(f, ">"),
             TokenValue::GreaterEqual => write!(f, ">="),
+            TokenValue::GreaterGreater => write!(f, ">>"),
             TokenValue::Ident(s) => write!(f, "{}", s),
             TokenValue::If => write!(f, "if"),
+            TokenValue::Import => write!(f, "import"),
             TokenValue::LeftBrace => write!(f, "["),
             TokenValue::LeftParen => write!(f, ")"),
             TokenValue::Less => write!(f, "<"),
             TokenValue::LessEqual => write!(f, "<=>"),
+            TokenValue::LessLess => write!(f, "<<"),
             TokenValue::Minus => write!(f, "-"),
+            TokenValue::MinusEqual => write!(f, "-="),
+            TokenValue::MinusMinus => write!(f, "--"),
             TokenValue::NewLine => write!(f, "<nl>"),
             TokenValue::Nil => write!(f, "nil"),
             TokenValue::Number(n) => write!(f, "{}", n),
             TokenValue::Or => write!(f, "or"),
+            TokenValue::Percent => write!(f, "%"),
+            TokenValue::Pipe => write!(f, "|"),
             TokenValue::Plus => write!(f, "+"),
+            TokenValue::PlusEqual => write!(f, "+="),
+            TokenValue::PlusPlus => write!(f, "++"),
             TokenValue::Print => write!(f, "print"),
+            TokenValue::Question => write!(f, "?"),
+            TokenValue::QuestionDot => write!(f, "?."),
+            TokenValue::QuestionQuestion => write!(f, "??"),
             TokenValue::Return => write!(f, "return"),
             TokenValue::RightBrace => write!(f, "]"),
             TokenValue::RightParen => write!(f, ")"),
             TokenValue::Semicolon => write!(f, ";"),
             TokenValue::Slash => write!(f, "/"),
+            TokenValue::SlashEqual => write!(f, "/="),
             TokenValue::Star => write!(f, "*"),
+            TokenValue::StarEqual => write!(f, "*="),
+            TokenValue::StarStar => write!(f, "**"),
             TokenValue::String(s) => write!(f, "{}", s),
             TokenValue::Super => write!(f, "super"),
             TokenValue::This => write!(f, "this"),
+            TokenValue::Throw => write!(f, "throw"),
+            TokenValue::Tilde => write!(f, "~"),
             TokenValue::True => write!(f, "true"),
+            TokenValue::Try => write!(f, "try"),
             TokenValue::Var => write!(f, "var"),
             TokenValue::Whitespace(_) => write!(f, "<ws>"),
             TokenValue::While => write!(f, "while"),
@@ -194,7 +311,10 @@ impl Display for TokenValue {
 }
 
 /// Represents a token found in the source content
+#[derive(PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Token {
+    #[cfg_attr(feature = "serde", serde(rename = "value"))]
     tkn_value: TokenValue,
     span: Span,
 }
@@ -205,6 +325,21 @@ impl Token {
         Self { tkn_value, span }
     }
 
+    /// Returns the value of the token
+    pub fn value(&self) -> &TokenValue {
+        &self.tkn_value
+    }
+
+    /// Returns the span of the token
+    pub fn span(&self) -> &Span {
+        &self.span
+    }
+
+    /// Moves the token span, see [Span::shift]
+    pub(crate) fn shift(&mut self, from: &Span, to: &Span) {
+        self.span.shift(from, to)
+    }
+
     /// Create a new left-paren token
     #[inline]
     pub(crate) fn left_paren(span: Span) -> Self {
@@ -229,6 +364,12 @@ impl Token {
         Token::new(TokenValue::RightBrace, span)
     }
 
+    /// Create a new colon token
+    #[inline]
+    pub(crate) fn colon(span: Span) -> Self {
+        Token::new(TokenValue::Colon, span)
+    }
+
     /// Create a new comma token
     #[inline]
     pub(crate) fn comma(span: Span) -> Self {
@@ -319,6 +460,114 @@ impl Token {
         Token::new(TokenValue::Slash, span)
     }
 
+    /// Create a new ampersand token
+    #[inline]
+    pub(crate) fn ampersand(span: Span) -> Self {
+        Token::new(TokenValue::Ampersand, span)
+    }
+
+    /// Create a new caret token
+    #[inline]
+    pub(crate) fn caret(span: Span) -> Self {
+        Token::new(TokenValue::Caret, span)
+    }
+
+    /// Create a new percent token
+    #[inline]
+    pub(crate) fn percent(span: Span) -> Self {
+        Token::new(TokenValue::Percent, span)
+    }
+
+    /// Create a new pipe token
+    #[inline]
+    pub(crate) fn pipe(span: Span) -> Self {
+        Token::new(TokenValue::Pipe, span)
+    }
+
+    /// Create a new question token
+    #[inline]
+    pub(crate) fn question(span: Span) -> Self {
+        Token::new(TokenValue::Question, span)
+    }
+
+    /// Create a new tilde token
+    #[inline]
+    pub(crate) fn tilde(span: Span) -> Self {
+        Token::new(TokenValue::Tilde, span)
+    }
+
+    /// Create a new greater-greater token
+    #[inline]
+    pub(crate) fn greater_greater(span: Span) -> Self {
+        Token::new(TokenValue::GreaterGreater, span)
+    }
+
+    /// Create a new less-less token
+    #[inline]
+    pub(crate) fn less_less(span: Span) -> Self {
+        Token::new(TokenValue::LessLess, span)
+    }
+
+    /// Create a new minus-equal token
+    #[inline]
+    pub(crate) fn minus_equal(span: Span) -> Self {
+        Token::new(TokenValue::MinusEqual, span)
+    }
+
+    /// Create a new minus-minus token
+    #[inline]
+    pub(crate) fn minus_minus(span: Span) -> Self {
+        Token::new(TokenValue::MinusMinus, span)
+    }
+
+    /// Create a new plus-equal token
+    #[inline]
+    pub(crate) fn plus_equal(span: Span) -> Self {
+        Token::new(TokenValue::PlusEqual, span)
+    }
+
+    /// Create a new plus-plus token
+    #[inline]
+    pub(crate) fn plus_plus(span: Span) -> Self {
+        Token::new(TokenValue::PlusPlus, span)
+    }
+
+    /// Create a new question-dot token
+    #[inline]
+    pub(crate) fn question_dot(span: Span) -> Self {
+        Token::new(TokenValue::QuestionDot, span)
+    }
+
+    /// Create a new question-question token
+    #[inline]
+    pub(crate) fn question_question(span: Span) -> Self {
+        Token::new(TokenValue::QuestionQuestion, span)
+    }
+
+    /// Create a new slash-equal token
+    #[inline]
+    pub(crate) fn slash_equal(span: Span) -> Self {
+        Token::new(TokenValue::SlashEqual, span)
+    }
+
+    /// Create a new star-equal token
+    #[inline]
+    pub(crate) fn star_equal(span: Span) -> Self {
+        Token::new(TokenValue::StarEqual, span)
+    }
+
+    /// Create a new star-star token
+    #[inline]
+    pub(crate) fn star_star(span: Span) -> Self {
+        Token::new(TokenValue::StarStar, span)
+    }
+
+    /// Create a new arrow token
+    #[inline]
+    pub(crate) fn arrow(span: Span) -> Self {
+        Token::new(TokenValue::Arrow, span)
+    }
+
     /// Create a new comment token
     #[inline]
     pub(crate) fn comment(c: String, span: Span) -> Self {
@@ -349,6 +598,12 @@ impl Token {
         Token::new(TokenValue::NewLine, span)
     }
 
+    /// Create an error token
+    #[inline]
+    pub(crate) fn error(e: String, span: Span) -> Self {
+        Token::new(TokenValue::Error(e), span)
+    }
+
     /// Create a identifier token
     #[inline]
     pub(crate) fn identifier(i: String, span: Span) -> Self {
@@ -380,6 +635,24 @@ impl Debug for Token {
 mod tests {
     use super::*;
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_json() {
+        let t = Token::identifier("language".to_string(), Span::new());
+        let json = serde_json::to_string(&t).unwrap();
+        assert_eq!(
+            json,
+            r#"{"value":{"Ident":"language"},"span":{"start_line":1,"start_col":0,"end_line":1,"end_col":0,"start_offset":0,"end_offset":0}}"#
+        );
+
+        let t1: Token = serde_json::from_str(&json).unwrap();
+        assert!(t == t1);
+
+        let t = Token::semicolon(Span::new());
+        let json = serde_json::to_string(&t.tkn_value).unwrap();
+        assert_eq!(json, r#""Semicolon""#);
+    }
+
     #[test]
     fn test_token_left_paren() {
         let t = Token::left_paren(Span::new());
@@ -412,6 +685,14 @@ mod tests {
         assert!(matches!(t.tkn_value, TokenValue::RightBrace));
     }
 
+    #[test]
+    fn test_token_colon() {
+        let t = Token::colon(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Colon));
+    }
+
     #[test]
     fn test_token_comma() {
         let t = Token::comma(Span::new());
@@ -532,6 +813,150 @@ mod tests {
         assert!(matches!(t.tkn_value, TokenValue::Slash));
     }
 
+    #[test]
+    fn test_token_ampersand() {
+        let t = Token::ampersand(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Ampersand));
+    }
+
+    #[test]
+    fn test_token_caret() {
+        let t = Token::caret(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Caret));
+    }
+
+    #[test]
+    fn test_token_percent() {
+        let t = Token::percent(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Percent));
+    }
+
+    #[test]
+    fn test_token_pipe() {
+        let t = Token::pipe(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Pipe));
+    }
+
+    #[test]
+    fn test_token_question() {
+        let t = Token::question(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Question));
+    }
+
+    #[test]
+    fn test_token_tilde() {
+        let t = Token::tilde(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Tilde));
+    }
+
+    #[test]
+    fn test_token_greater_greater() {
+        let t = Token::greater_greater(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::GreaterGreater));
+    }
+
+    #[test]
+    fn test_token_less_less() {
+        let t = Token::less_less(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::LessLess));
+    }
+
+    #[test]
+    fn test_token_minus_equal() {
+        let t = Token::minus_equal(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::MinusEqual));
+    }
+
+    #[test]
+    fn test_token_minus_minus() {
+        let t = Token::minus_minus(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::MinusMinus));
+    }
+
+    #[test]
+    fn test_token_plus_equal() {
+        let t = Token::plus_equal(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::PlusEqual));
+    }
+
+    #[test]
+    fn test_token_plus_plus() {
+        let t = Token::plus_plus(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::PlusPlus));
+    }
+
+    #[test]
+    fn test_token_question_dot() {
+        let t = Token::question_dot(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::QuestionDot));
+    }
+
+    #[test]
+    fn test_token_question_question() {
+        let t = Token::question_question(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::QuestionQuestion));
+    }
+
+    #[test]
+    fn test_token_slash_equal() {
+        let t = Token::slash_equal(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::SlashEqual));
+    }
+
+    #[test]
+    fn test_token_star_equal() {
+        let t = Token::star_equal(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::StarEqual));
+    }
+
+    #[test]
+    fn test_token_star_star() {
+        let t = Token::star_star(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::StarStar));
+    }
+
+    #[test]
+    fn test_token_arrow() {
+        let t = Token::arrow(Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Arrow));
+    }
+
     #[test]
     fn test_token_comment() {
         let t = Token::comment("test".to_string(), Span::new());
@@ -588,6 +1013,17 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_token_error() {
+        let t = Token::error("test".to_string(), Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        match t.tkn_value {
+            TokenValue::Error(c) => assert_eq!(c, "test"),
+            _ => unreachable!(),
+        }
+    }
+
     #[test]
     fn test_token_newline() {
         let t = Token::newline(Span::new());
@@ -620,6 +1056,14 @@ mod tests {
         assert!(matches!(t.tkn_value, TokenValue::Or));
     }
 
+    #[test]
+    fn test_token_as() {
+        let t = Token::new(TokenValue::As, Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::As));
+    }
+
     #[test]
     fn test_token_class() {
         let t = Token::new(TokenValue::Class, Span::new());
@@ -676,6 +1120,14 @@ mod tests {
         assert!(matches!(t.tkn_value, TokenValue::For));
     }
 
+    #[test]
+    fn test_token_from() {
+        let t = Token::new(TokenValue::From, Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::From));
+    }
+
     #[test]
     fn test_token_if() {
         let t = Token::new(TokenValue::If, Span::new());
@@ -684,6 +1136,14 @@ mod tests {
         assert!(matches!(t.tkn_value, TokenValue::If));
     }
 
+    #[test]
+    fn test_token_import() {
+        let t = Token::new(TokenValue::Import, Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Import));
+    }
+
     #[test]
     fn test_token_nil() {
         let t = Token::new(TokenValue::Nil, Span::new());
@@ -731,4 +1191,52 @@ mod tests {
         assert!(!format!("{:?}", t).is_empty());
         assert!(matches!(t.tkn_value, TokenValue::While));
     }
+
+    #[test]
+    fn test_token_catch() {
+        let t = Token::new(TokenValue::Catch, Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Catch));
+    }
+
+    #[test]
+    fn test_token_finally() {
+        let t = Token::new(TokenValue::Finally, Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Finally));
+    }
+
+    #[test]
+    fn test_token_throw() {
+        let t = Token::new(TokenValue::Throw, Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Throw));
+    }
+
+    #[test]
+    fn test_token_try() {
+        let t = Token::new(TokenValue::Try, Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Try));
+    }
+
+    #[test]
+    fn test_token_break() {
+        let t = Token::new(TokenValue::Break, Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Break));
+    }
+
+    #[test]
+    fn test_token_continue() {
+        let t = Token::new(TokenValue::Continue, Span::new());
+        assert!(!format!("{}", t).is_empty());
+        assert!(!format!("{:?}", t).is_empty());
+        assert!(matches!(t.tkn_value, TokenValue::Continue));
+    }
 }
@@ -15,22 +15,34 @@
 //! later, higher-precedence rules only.
 //!
 //! ```text
-//! declaration → varDecl
+//! declaration → importDecl
+//!             → varDecl
 //!             → funDecl
 //!             → classDecl
 //!             | statement ;
 //!
+//! importDecl  → "import" STRING "as" IDENTIFIER ";"
+//!             | "from" STRING "import" IDENTIFIER ( "," IDENTIFIER )* ";" ;
+//! varDecl     → "var" IDENTIFIER ( ":" type )? ( "=" expression )? ";" ;
 //! funDecl     → "fun" function ;
-//! classDecl   → "class" IDENTIFIER "{" function* "}" ;
-//! function    → IDENTIFIER "(" params? ")" block ;
-//! params      → IDENTIFIER ( "," IDENTIFIER )* ;
+//! classDecl   → "class" IDENTIFIER "{" ( field | function )* "}" ;
+//! field       → IDENTIFIER ":" type ";" ;
+//! function    → IDENTIFIER "(" params? ")" ( "->" type )? block ;
+//! params      → param ( "," param )* ;
+//! param       → IDENTIFIER ( ":" type )? ;
+//! type        → IDENTIFIER ;
 //!
 //! statement → exprStmt
 //!           | ifStmt
 //!           | forStmt
 //!           | whileStmt
+//!           | loopStmt
+//!           | breakStmt
+//!           | continueStmt
 //!           | printStmt
 //!           | returnStmt
+//!           | throwStmt
+//!           | tryStmt
 //!           | block ;
 //!
 //! exprStmt  → expr ";" ;
@@ -39,8 +51,15 @@
 //!                       expression? ";"
 //!                       expression? ")" statement ;
 //! whileStmt → "while" "(" expression ")" statement ;
+//! loopStmt  → IDENTIFIER ":" ( forStmt | whileStmt ) ;
+//! breakStmt → "break" IDENTIFIER? ";" ;
+//! continueStmt → "continue" IDENTIFIER? ";" ;
 //! printStmt → "print" expr ";" ;
 //! returnStmt → "return" expression? ";" ;
+//! throwStmt → "throw" expression ";" ;
+//! tryStmt   → "try" block ( catch finally? | finally ) ;
+//! catch     → "catch" "(" IDENTIFIER ")" block ;
+//! finally   → "finally" block ;
 //! block     → "{" declaration* "}" ;
 //! ```
 //!
@@ -50,18 +69,27 @@
 //!
 //! ```text
 //! expression     → assign ;
-//! assign         → ( call "." )? IDENTIFIER "=" assignment
-//!                | logic_or;
+//! assign         → ( call "." )? IDENTIFIER
+//!                  ( "=" | "+=" | "-=" | "*=" | "/=" ) assignment
+//!                | ternary ;
+//! ternary        → nil_coalesce ( "?" expression ":" ternary )? ;
+//! nil_coalesce   → logic_or ( "??" logic_or )* ;
 //! logic_or       → logic_and ( "or" logic_and )* ;
 //! logic_and      → equality ( "and" equality )* ;
 //!
 //! equality       → comparison ( ( "!=" | "==" ) comparison )* ;
-//! comparison     → addition ( ( ">" | ">=" | "<" | "<=" ) addition )* ;
+//! comparison     → bit_or ( ( ">" | ">=" | "<" | "<=" ) bit_or )* ;
+//! bit_or         → bit_xor ( "|" bit_xor )* ;
+//! bit_xor        → bit_and ( "^" bit_and )* ;
+//! bit_and        → shift ( "&" shift )* ;
+//! shift          → addition ( ( "<<" | ">>" ) addition )* ;
 //! addition       → multiplication ( ( "-" | "+" ) multiplication )* ;
-//! multiplication → unary ( ( "/" | "*" ) unary )* ;
-//! unary          → ( "!" | "-" ) unary
-//!                | call ;
-//! call           → primary ( "(" args? ")" | "." IDENTIFIER )* ;
+//! multiplication → unary ( ( "/" | "*" | "%" ) unary )* ;
+//! unary          → ( "!" | "-" | "~" | "++" | "--" ) unary
+//!                | power ;
+//! power          → postfix ( "**" unary )? ;
+//! postfix        → call ( "++" | "--" )? ;
+//! call           → primary ( "(" args? ")" | ( "." | "?." ) IDENTIFIER )* ;
 //! primary        → "true" | "false" | "nil" | "this"
 //!                | NUMBER | STRING | IDENTIFIER | "(" expr ")"
 //!                | "super" "." IDENTIFIER ;
@@ -1,19 +1,73 @@
+use nyxx_lexer::{Lexer, Token, TokenValue};
 use std::io::{self, Write};
 
 fn main() {
     let _ = env_logger::builder().try_init();
     let args: Vec<String> = std::env::args().skip(1).collect();
 
+    if args.first().map(String::as_str) == Some("tokens") {
+        dump_tokens(&args[1..]);
+        return;
+    }
+
     match args.len().cmp(&1) {
         std::cmp::Ordering::Equal => run_file(args[0].clone()),
-        std::cmp::Ordering::Greater => {
-            eprintln!("usage: nyxx-in [script]");
-            std::process::exit(64);
-        }
+        std::cmp::Ordering::Greater => usage(),
         _ => run_prompt(),
     }
 }
 
+fn usage() -> ! {
+    eprintln!("usage: nyxx-in [script]");
+    eprintln!("       nyxx-in tokens [--format=text|json] <script>");
+    std::process::exit(64);
+}
+
+fn dump_tokens(args: &[String]) {
+    let (format, file_path) = match args {
+        [file_path] => ("text", file_path),
+        [format, file_path] => match format.strip_prefix("--format=") {
+            Some(format) => (format, file_path),
+            None => usage(),
+        },
+        _ => usage(),
+    };
+
+    if format != "text" && format != "json" {
+        usage();
+    }
+
+    let buffer = std::fs::read_to_string(file_path).expect("Failed to read file");
+    let tokens: Vec<Token> = Lexer::iter(&buffer).collect();
+
+    let errors = lex_errors(&tokens);
+    if !errors.is_empty() {
+        errors.iter().for_each(|e| eprintln!("{}", e));
+        std::process::exit(65);
+    }
+
+    write_tokens(&mut io::stdout(), &tokens, format).expect("Failed to write tokens");
+}
+
+fn lex_errors(tokens: &[Token]) -> Vec<String> {
+    tokens
+        .iter()
+        .filter_map(|t| match t.value() {
+            TokenValue::Error(e) => Some(format!("{} Error: {}", t.span(), e)),
+            _ => None,
+        })
+        .collect()
+}
+
+fn write_tokens(out: &mut impl Write, tokens: &[Token], format: &str) -> io::Result<()> {
+    if format == "json" {
+        serde_json::to_writer_pretty(&mut *out, tokens)?;
+        writeln!(out)
+    } else {
+        tokens.iter().try_for_each(|t| writeln!(out, "{:?}", t))
+    }
+}
+
 fn run_file(file_path: String) {
     let buffer = std::fs::read_to_string(&file_path).expect("Failed to read file");
     run(&buffer);
@@ -45,3 +99,39 @@ fn run_prompt() {
 fn run(script: &str) {
     eprintln!("running script [{}]", script);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lex_errors() {
+        let tokens: Vec<Token> = Lexer::iter("a @ b;\n\"abc").collect();
+        assert_eq!(
+            lex_errors(&tokens),
+            vec![
+                "[1:2] Error: Unexpected character '@'",
+                "[2:0..4] Error: Unterminated string",
+            ]
+        );
+
+        let tokens: Vec<Token> = Lexer::iter("var s = \"a\";").collect();
+        assert!(lex_errors(&tokens).is_empty());
+    }
+
+    #[test]
+    fn test_write_tokens_json() {
+        let tokens: Vec<Token> = Lexer::iter("var s = \"a\";").collect();
+        let mut out = Vec::new();
+        write_tokens(&mut out, &tokens, "json").unwrap();
+
+        let json: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        let values: Vec<&serde_json::Value> = json
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| &t["value"])
+            .collect();
+        assert!(values.contains(&&serde_json::json!({ "String": "a" })));
+    }
+}